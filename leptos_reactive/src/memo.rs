@@ -79,7 +79,132 @@ pub fn create_memo<T>(
 where
     T: PartialEq + 'static,
 {
-    cx.runtime.create_memo(f)
+    create_memo_with_compare(cx, f, |curr_value, new_value| {
+        curr_value != Some(new_value)
+    })
+}
+
+/// Creates an efficient derived reactive value based on other reactive values,
+/// using a custom comparison function to decide whether dependents should be
+/// notified.
+///
+/// This is identical to [`create_memo`], except that it does not require
+/// `T: PartialEq`. Instead, you supply a `compare` function that is called
+/// with the previous value (`None` for the initial computation) and the
+/// newly-computed value, and returns `true` if dependents should be
+/// notified. This is useful for memoizing types that don't implement
+/// `PartialEq`, or for using a cheaper or more appropriate notion of
+/// equality than `PartialEq` provides, e.g. pointer equality on `Rc<T>` or
+/// comparing only an id field of a large struct.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # use std::{cell::Cell, rc::Rc};
+/// # create_scope(create_runtime(), |cx| {
+/// let (value, set_value) = create_signal(cx, Rc::new(0));
+///
+/// // compares by pointer identity rather than by value
+/// let memoized = create_memo_with_compare(
+///     cx,
+///     move |_| value(),
+///     |prev, new| !prev.is_some_and(|prev| Rc::ptr_eq(prev, new)),
+/// );
+///
+/// let runs = Rc::new(Cell::new(0));
+/// create_effect(cx, {
+///     let runs = Rc::clone(&runs);
+///     move |_| {
+///         memoized();
+///         runs.set(runs.get() + 1);
+///     }
+/// });
+/// assert_eq!(runs.get(), 1);
+///
+/// // setting the same pointer again doesn't notify dependents of the memo
+/// let same_rc = value.get_untracked();
+/// set_value(same_rc);
+/// assert_eq!(runs.get(), 1);
+///
+/// // a genuinely new value does notify, and the memo reflects it
+/// set_value(Rc::new(1));
+/// assert_eq!(runs.get(), 2);
+/// assert_eq!(*memoized.get(), 1);
+/// # }).dispose();
+/// ```
+#[cfg_attr(
+    debug_assertions,
+    instrument(
+        level = "trace",
+        skip_all,
+        fields(
+            scope = ?cx.id,
+            ty = %std::any::type_name::<T>()
+        )
+    )
+)]
+#[track_caller]
+pub fn create_memo_with_compare<T>(
+    cx: Scope,
+    f: impl Fn(Option<&T>) -> T + 'static,
+    compare: impl Fn(Option<&T>, &T) -> bool + 'static,
+) -> Memo<T>
+where
+    T: 'static,
+{
+    cx.runtime.create_memo_with_compare(f, compare)
+}
+
+/// Creates a lazy, pull-based derived reactive value based on other reactive
+/// values.
+///
+/// Unlike [`create_memo`], a lazy memo does not run its computation eagerly
+/// on creation or on every dependency change. Instead, it is marked stale
+/// when a dependency changes, and is only recomputed the next time its
+/// value is actually read through [`SignalWith`] or [`SignalGet`]. If
+/// nothing ever reads the memo, its computation never runs at all, and if
+/// it is read multiple times while stale, it still only recomputes once.
+///
+/// This is most useful for memo chains in which an intermediate memo feeds
+/// an expensive downstream computation that may be conditionally read, e.g.
+/// only inside one branch of a `<Show/>`.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// let (value, set_value) = create_signal(cx, 0);
+///
+/// // not computed yet: nothing has read it
+/// let lazy = create_lazy_memo(cx, move |_| value() * 2);
+/// set_value(1);
+/// set_value(2);
+///
+/// // computed here, for the first time, reflecting the latest value
+/// assert_eq!(lazy.get(), 4);
+/// # }).dispose();
+/// ```
+#[cfg_attr(
+    debug_assertions,
+    instrument(
+        level = "trace",
+        skip_all,
+        fields(
+            scope = ?cx.id,
+            ty = %std::any::type_name::<T>()
+        )
+    )
+)]
+#[track_caller]
+pub fn create_lazy_memo<T>(
+    cx: Scope,
+    f: impl Fn(Option<&T>) -> T + 'static,
+) -> Memo<T>
+where
+    T: PartialEq + 'static,
+{
+    cx.runtime.create_lazy_memo(
+        f,
+        |curr_value: Option<&T>, new_value: &T| curr_value != Some(new_value),
+    )
 }
 
 /// An efficient derived reactive value based on other reactive values.
@@ -193,10 +318,19 @@ impl<T: Clone> SignalGetUntracked<T> for Memo<T> {
     )]
     fn get_untracked(&self) -> T {
         with_runtime(self.runtime, move |runtime| {
-            let f = move |maybe_value: &Option<T>| maybe_value.clone().unwrap();
+            runtime.update_if_necessary(self.id);
+            let f = move |maybe_value: &Option<T>| {
+                maybe_value.clone().ok_or(MemoError::Uninitialized)
+            };
             match self.id.try_with_no_subscription(runtime, f) {
-                Ok(t) => t,
-                Err(_) => panic_getting_dead_memo(
+                Ok(Ok(t)) => t,
+                Ok(Err(error)) => panic_reading_memo(
+                    error,
+                    #[cfg(debug_assertions)]
+                    self.defined_at,
+                ),
+                Err(_) => panic_reading_memo(
+                    MemoError::Disposed,
                     #[cfg(debug_assertions)]
                     self.defined_at,
                 ),
@@ -220,6 +354,7 @@ impl<T: Clone> SignalGetUntracked<T> for Memo<T> {
     )]
     fn try_get_untracked(&self) -> Option<T> {
         with_runtime(self.runtime, move |runtime| {
+            runtime.update_if_necessary(self.id);
             self.id.try_with_no_subscription(runtime, T::clone).ok()
         })
         .ok()
@@ -242,12 +377,20 @@ impl<T> SignalWithUntracked<T> for Memo<T> {
         )
     )]
     fn with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> O {
-        // Unwrapping here is fine for the same reasons as <Memo as
-        // UntrackedSignal>::get_untracked
         with_runtime(self.runtime, |runtime| {
-            match self.id.try_with_no_subscription(runtime, |v: &T| f(v)) {
-                Ok(t) => t,
-                Err(_) => panic_getting_dead_memo(
+            runtime.update_if_necessary(self.id);
+            let f = move |maybe_value: &Option<T>| {
+                maybe_value.as_ref().map(f).ok_or(MemoError::Uninitialized)
+            };
+            match self.id.try_with_no_subscription(runtime, f) {
+                Ok(Ok(t)) => t,
+                Ok(Err(error)) => panic_reading_memo(
+                    error,
+                    #[cfg(debug_assertions)]
+                    self.defined_at,
+                ),
+                Err(_) => panic_reading_memo(
+                    MemoError::Disposed,
                     #[cfg(debug_assertions)]
                     self.defined_at,
                 ),
@@ -271,6 +414,7 @@ impl<T> SignalWithUntracked<T> for Memo<T> {
     )]
     fn try_with_untracked<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
         with_runtime(self.runtime, |runtime| {
+            runtime.update_if_necessary(self.id);
             self.id.try_with_no_subscription(runtime, |v: &T| f(v)).ok()
         })
         .ok()
@@ -348,9 +492,10 @@ impl<T> SignalWith<T> for Memo<T> {
     )]
     #[track_caller]
     fn with<O>(&self, f: impl FnOnce(&T) -> O) -> O {
-        match self.try_with(f) {
-            Some(t) => t,
-            None => panic_getting_dead_memo(
+        match self.try_with_state(f) {
+            Ok(t) => t,
+            Err(error) => panic_reading_memo(
+                error,
                 #[cfg(debug_assertions)]
                 self.defined_at,
             ),
@@ -372,21 +517,83 @@ impl<T> SignalWith<T> for Memo<T> {
     )]
     #[track_caller]
     fn try_with<O>(&self, f: impl FnOnce(&T) -> O) -> Option<O> {
-        // memo is stored as Option<T>, but will always have T available
-        // after latest_value() called, so we can unwrap safely
-        let f = move |maybe_value: &Option<T>| f(maybe_value.as_ref().unwrap());
+        self.try_with_state(f).ok()
+    }
+}
+
+impl<T> Memo<T>
+where
+    T: 'static,
+{
+    /// Applies a function to the current value of the memo, distinguishing
+    /// a memo whose reactive node has been disposed from one that simply
+    /// hasn't computed a value yet.
+    ///
+    /// This is useful for memos that may legitimately be read before they
+    /// have a value, e.g. a memo read out of order by async/suspense code.
+    /// A stale or never-computed [lazy memo](create_lazy_memo) is brought up
+    /// to date before it is read, as with any other read. For the common
+    /// case, prefer [`SignalWith::with`]/[`SignalGet::get`], which panic
+    /// instead of returning a [`MemoError`].
+    #[cfg_attr(
+        debug_assertions,
+        instrument(
+            level = "trace",
+            name = "Memo::try_with_state()",
+            skip_all,
+            fields(
+                id = ?self.id,
+                defined_at = %self.defined_at,
+                ty = %std::any::type_name::<T>()
+            )
+        )
+    )]
+    #[track_caller]
+    pub fn try_with_state<O>(
+        &self,
+        f: impl FnOnce(&T) -> O,
+    ) -> Result<O, MemoError> {
+        let f = move |maybe_value: &Option<T>| {
+            maybe_value.as_ref().map(f).ok_or(MemoError::Uninitialized)
+        };
 
         let diagnostics = diagnostics!(self);
 
         with_runtime(self.runtime, |runtime| {
+            runtime.update_if_necessary(self.id);
             self.id.subscribe(runtime, diagnostics);
-            self.id.try_with_no_subscription(runtime, f).ok()
+            self.id
+                .try_with_no_subscription(runtime, f)
+                .unwrap_or(Err(MemoError::Disposed))
         })
-        .ok()
-        .flatten()
+        .unwrap_or(Err(MemoError::Disposed))
     }
 }
 
+/// Errors that can occur when reading the value of a [`Memo`] through
+/// [`Memo::try_with_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoError {
+    /// The memo's reactive node has been disposed, so its value can no
+    /// longer be read.
+    Disposed,
+    /// The memo does not hold a computed value at the time it is read.
+    Uninitialized,
+}
+
+impl std::fmt::Display for MemoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MemoError::Disposed => write!(f, "memo has already been disposed"),
+            MemoError::Uninitialized => {
+                write!(f, "memo has not yet computed a value")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MemoError {}
+
 impl<T: Clone> SignalStream<T> for Memo<T> {
     #[cfg_attr(
         debug_assertions,
@@ -429,21 +636,24 @@ impl<T> SignalDispose for Memo<T> {
 
 impl_get_fn_traits![Memo];
 
-pub(crate) struct MemoState<T, F>
+pub(crate) struct MemoState<T, F, C>
 where
-    T: PartialEq + 'static,
+    T: 'static,
     F: Fn(Option<&T>) -> T,
+    C: Fn(Option<&T>, &T) -> bool,
 {
     pub f: F,
+    pub compare: C,
     pub t: PhantomData<T>,
     #[cfg(debug_assertions)]
     pub(crate) defined_at: &'static std::panic::Location<'static>,
 }
 
-impl<T, F> AnyComputation for MemoState<T, F>
+impl<T, F, C> AnyComputation for MemoState<T, F, C>
 where
-    T: PartialEq + 'static,
+    T: 'static,
     F: Fn(Option<&T>) -> T,
+    C: Fn(Option<&T>, &T) -> bool,
 {
     #[cfg_attr(
         debug_assertions,
@@ -466,7 +676,7 @@ where
 
             // run the effect
             let new_value = (self.f)(curr_value.as_ref());
-            let is_different = curr_value.as_ref() != Some(&new_value);
+            let is_different = (self.compare)(curr_value.as_ref(), &new_value);
             (new_value, is_different)
         };
         if is_different {
@@ -516,3 +726,28 @@ pub(crate) fn panic_getting_dead_memo(
         )
     )
 }
+
+/// Panics with a message appropriate to the given [`MemoError`], rather
+/// than always claiming the memo was disposed: a memo that does not hold a
+/// computed value yet gets its own message, distinct from an
+/// actually-disposed one.
+#[track_caller]
+fn panic_reading_memo(
+    error: MemoError,
+    #[cfg(debug_assertions)] defined_at: &'static std::panic::Location<'static>,
+) -> ! {
+    match error {
+        MemoError::Disposed => panic_getting_dead_memo(
+            #[cfg(debug_assertions)]
+            defined_at,
+        ),
+        MemoError::Uninitialized => panic!(
+            "{}",
+            format_memo_warning(
+                "Attempted to get a memo before it has computed a value.",
+                #[cfg(debug_assertions)]
+                defined_at,
+            )
+        ),
+    }
+}
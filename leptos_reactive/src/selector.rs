@@ -0,0 +1,156 @@
+#![forbid(unsafe_code)]
+use crate::{
+    create_effect, with_runtime, NodeId, RuntimeId, Scope,
+};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    hash::Hash,
+    rc::Rc,
+};
+
+/// Creates a conditional signal that only notifies subscribers when a
+/// selected key changes, rather than notifying every subscriber of every
+/// change to the source value.
+///
+/// This is useful when you are tracking a list of items, but only want to
+/// notify the item that was selected and the item that was previously
+/// selected, instead of every item in the list. For a list of `N` rows each
+/// asking "am I selected?", a derived signal re-runs all `N` rows on every
+/// change; a selector only re-runs the (at most) two rows whose answer
+/// actually changed.
+///
+/// ```
+/// # use leptos_reactive::*;
+/// # create_scope(create_runtime(), |cx| {
+/// let (selected, set_selected) = create_signal(cx, 0);
+/// let is_selected = create_selector(cx, selected);
+///
+/// // subscribes specifically to changes involving the key `5`
+/// assert_eq!(is_selected.selected(5), false);
+/// set_selected(5);
+/// assert_eq!(is_selected.selected(5), true);
+/// # }).dispose();
+/// ```
+#[cfg_attr(
+    debug_assertions,
+    instrument(
+        level = "trace",
+        skip_all,
+        fields(
+            scope = ?cx.id,
+            ty = %std::any::type_name::<T>()
+        )
+    )
+)]
+#[track_caller]
+pub fn create_selector<T>(
+    cx: Scope,
+    source: impl Fn() -> T + 'static,
+) -> Selector<T>
+where
+    T: PartialEq + Eq + Clone + Hash + 'static,
+{
+    let subs: Rc<RefCell<HashMap<T, HashSet<NodeId>>>> = Default::default();
+    let current_value = Rc::new(RefCell::new(None::<T>));
+
+    {
+        let subs = Rc::clone(&subs);
+        let current_value = Rc::clone(&current_value);
+        let runtime = cx.runtime;
+        create_effect(cx, move |_| {
+            let new_value = source();
+            let old_value = current_value.borrow_mut().replace(new_value.clone());
+
+            if old_value.as_ref() != Some(&new_value) {
+                let mut subs = subs.borrow_mut();
+                with_runtime(runtime, |runtime| {
+                    if let Some(old_value) = &old_value {
+                        if let Some(nodes) = subs.get(old_value) {
+                            for node in nodes {
+                                runtime.mark_dirty(*node);
+                            }
+                        }
+                    }
+                    if let Some(nodes) = subs.get(&new_value) {
+                        for node in nodes {
+                            runtime.mark_dirty(*node);
+                        }
+                    }
+                })
+                .expect("runtime to be alive");
+                if let Some(old_value) = &old_value {
+                    if subs.get(old_value).is_some_and(HashSet::is_empty) {
+                        subs.remove(old_value);
+                    }
+                }
+                if subs.get(&new_value).is_some_and(HashSet::is_empty) {
+                    subs.remove(&new_value);
+                }
+            }
+        });
+    }
+
+    Selector {
+        runtime: cx.runtime,
+        subs,
+        current_value,
+    }
+}
+
+/// A conditional signal that efficiently tracks whether a value is equal to
+/// some selected key, notifying only the subscribers affected by a given
+/// change. See [`create_selector`].
+pub struct Selector<T: 'static> {
+    runtime: RuntimeId,
+    subs: Rc<RefCell<HashMap<T, HashSet<NodeId>>>>,
+    current_value: Rc<RefCell<Option<T>>>,
+}
+
+impl<T> Clone for Selector<T> {
+    fn clone(&self) -> Self {
+        Self {
+            runtime: self.runtime,
+            subs: Rc::clone(&self.subs),
+            current_value: Rc::clone(&self.current_value),
+        }
+    }
+}
+
+impl<T> Debug for Selector<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Selector").finish()
+    }
+}
+
+impl<T> Selector<T>
+where
+    T: PartialEq + Eq + Clone + Hash + 'static,
+{
+    /// Reactively checks whether the given key is currently selected,
+    /// subscribing the caller only to changes involving that specific key.
+    #[track_caller]
+    pub fn selected(&self, key: T) -> bool {
+        with_runtime(self.runtime, |runtime| {
+            if let Some(observer) = runtime.observer() {
+                let mut subs = self.subs.borrow_mut();
+                subs.entry(key.clone()).or_default().insert(observer);
+
+                let subs = Rc::clone(&self.subs);
+                runtime.on_cleanup(observer, move || {
+                    let mut subs = subs.borrow_mut();
+                    if let Some(nodes) = subs.get_mut(&key) {
+                        nodes.remove(&observer);
+                        if nodes.is_empty() {
+                            subs.remove(&key);
+                        }
+                    }
+                });
+            }
+        })
+        .expect("runtime to be alive");
+
+        self.current_value.borrow().as_ref() == Some(&key)
+    }
+}